@@ -23,4 +23,31 @@ fn main() {
 
     println!("f32: {}", rand.rand_f32());
     println!("f64: {}", rand.rand_f64());
+
+    println!("1..=6: {}", rand.gen_range_u32(1..=6));
+    println!("-10..10: {}", rand.gen_range_i32(-10..10));
+
+    let mut deck: Vec<&str> = vec!["jack", "queen", "king", "ace"];
+    rand.shuffle(&mut deck);
+    println!("shuffled: {:?}", deck);
+    println!("choose: {:?}", rand.choose(&deck));
+    println!("choose_multiple: {:?}", rand.choose_multiple(0..52, 5));
+
+    let items = ["common", "rare", "legendary"];
+    let weights = [80.0, 19.0, 1.0];
+    println!(
+        "choose_weighted: {:?}",
+        rand.choose_weighted(&items, &weights)
+    );
+
+    println!("normal: {}", rand.rand_normal(0.0, 1.0));
+    println!("exp: {}", rand.rand_exp(1.0));
+
+    println!(
+        "iter_u64: {:?}",
+        rand.iter_u64().take(3).collect::<Vec<_>>()
+    );
+
+    let mut isaac_rand = Random::with_seed(RandomSeedSource::SystemTime);
+    println!("isaac u64: {}", isaac_rand.rand_u64());
 }
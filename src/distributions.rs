@@ -0,0 +1,105 @@
+use crate::{Random, RngCore};
+
+impl<C: RngCore> Random<C> {
+    /// Sample from a normal (Gaussian) distribution with the given `mean` and `std_dev`,
+    /// using the [Box–Muller transform](https://en.wikipedia.org/wiki/Box%E2%80%93Muller_transform).
+    pub fn rand_normal(&mut self, mean: f64, std_dev: f64) -> f64 {
+        // u1 must be in (0.0, 1.0] since `ln(0.0)` is undefined, but `rand_f64` can return
+        // exactly 0.0.
+        let u1 = loop {
+            let u1 = self.rand_f64();
+            if u1 > 0.0 {
+                break u1;
+            }
+        };
+        let u2 = self.rand_f64();
+
+        let z = (-2.0 * u1.ln()).sqrt() * (2.0 * core::f64::consts::PI * u2).cos();
+        mean + std_dev * z
+    }
+
+    /// Sample from an exponential distribution with rate `lambda`, using inverse-CDF
+    /// sampling.
+    pub fn rand_exp(&mut self, lambda: f64) -> f64 {
+        // u must be in [0.0, 1.0) so `1.0 - u` never hits 0.0, which would send `ln` to
+        // negative infinity.
+        let u = loop {
+            let u = self.rand_f64();
+            if u < 1.0 {
+                break u;
+            }
+        };
+        -(1.0 - u).ln() / lambda
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Random, RandomSeedSource};
+
+    fn rand() -> Random {
+        Random::new(RandomSeedSource::Manual(42))
+    }
+
+    // A seed with more entropy than `rand()`'s small `Manual(42)`: the LFSR starts from the
+    // seed bit pattern directly, and a mostly-zero seed takes many rotations to diffuse,
+    // which would otherwise skew these mean-based statistical checks.
+    fn high_entropy_rand() -> Random {
+        Random::new(RandomSeedSource::Manual(
+            0x1234_5678_9abc_def0_1234_5678_9abc_def0,
+        ))
+    }
+
+    #[test]
+    fn rand_normal_outputs_are_finite() {
+        let mut rand = rand();
+        for _ in 0..10_000 {
+            assert!(rand.rand_normal(0.0, 1.0).is_finite());
+        }
+    }
+
+    #[test]
+    fn rand_exp_outputs_are_finite() {
+        let mut rand = rand();
+        for _ in 0..10_000 {
+            assert!(rand.rand_exp(1.0).is_finite());
+        }
+    }
+
+    #[test]
+    fn rand_normal_sample_mean_is_within_a_few_sigma() {
+        let mut rand = high_entropy_rand();
+        let mean = 10.0;
+        let std_dev = 2.0;
+        let draws = 50_000;
+
+        let sum: f64 = (0..draws).map(|_| rand.rand_normal(mean, std_dev)).sum();
+        let sample_mean = sum / draws as f64;
+
+        // Standard error of the mean shrinks with sqrt(draws), so a handful of sigma is a
+        // generous margin for a deterministic seed.
+        let standard_error = std_dev / (draws as f64).sqrt();
+        assert!(
+            (sample_mean - mean).abs() < 5.0 * standard_error,
+            "expected sample mean near {mean}, got {sample_mean}"
+        );
+    }
+
+    #[test]
+    fn rand_exp_sample_mean_is_within_a_few_sigma() {
+        let mut rand = high_entropy_rand();
+        let lambda = 0.5;
+        let expected_mean = 1.0 / lambda;
+        let draws = 50_000;
+
+        let sum: f64 = (0..draws).map(|_| rand.rand_exp(lambda)).sum();
+        let sample_mean = sum / draws as f64;
+
+        // The exponential distribution's standard deviation equals its mean.
+        let standard_error = expected_mean / (draws as f64).sqrt();
+        assert!(
+            (sample_mean - expected_mean).abs() < 5.0 * standard_error,
+            "expected sample mean near {expected_mean}, got {sample_mean}"
+        );
+    }
+}
@@ -0,0 +1,35 @@
+use crate::{Random, RngCore};
+
+/// An unbounded iterator yielding values from the corresponding `rand_*` method.
+///
+/// Created by [`Random::iter_u64`], [`Random::iter_bool`], and [`Random::iter_f64`].
+pub struct RandIter<'a, C: RngCore, T> {
+    rand: &'a mut Random<C>,
+    next: fn(&mut Random<C>) -> T,
+}
+
+impl<C: RngCore, T> Iterator for RandIter<'_, C, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        Some((self.next)(self.rand))
+    }
+}
+
+macro_rules! implement_iter {
+    ($T: ty, $func_name: ident, $rand_func: ident) => {
+        #[doc = concat!("Returns an unbounded iterator of [`Self::", stringify!($rand_func), "`] draws.")]
+        pub fn $func_name(&mut self) -> RandIter<'_, C, $T> {
+            RandIter {
+                rand: self,
+                next: Self::$rand_func,
+            }
+        }
+    };
+}
+
+impl<C: RngCore> Random<C> {
+    implement_iter!(u64, iter_u64, rand_u64);
+    implement_iter!(bool, iter_bool, rand_bool);
+    implement_iter!(f64, iter_f64, rand_f64);
+}
@@ -1,6 +1,9 @@
 //! A simple random number generator library.
 //!
-//! The random number generation is based on LFSR ([Linear-feedback shift register](https://en.wikipedia.org/wiki/Linear-feedback_shift_register)).
+//! By default, random number generation is based on LFSR ([Linear-feedback shift
+//! register](https://en.wikipedia.org/wiki/Linear-feedback_shift_register)). An
+//! [`Isaac`]-backed generator is also available for when the output needs to be
+//! cryptographically strong rather than merely fast.
 //!
 //! ```rust
 //! use randlib::{Random, RandomSeedSource};
@@ -13,14 +16,39 @@
 //!     println!("u32:  {}", rand.rand_u32());
 //!     println!("bool: {}", rand.rand_bool());
 //!     println!("f32:  {}", rand.rand_f32());
+//!
+//!     // Unbiased sampling from an arbitrary range.
+//!     println!("1..=6: {}", rand.gen_range_u32(1..=6));
+//!
+//!     // Slice/iterator helpers.
+//!     let mut deck = vec!["jack", "queen", "king", "ace"];
+//!     rand.shuffle(&mut deck);
+//!     println!("shuffled: {:?}", deck);
+//!     println!("drawn: {:?}", rand.choose(&deck));
+//!
+//!     // Normal and exponential distributions.
+//!     println!("normal: {}", rand.rand_normal(0.0, 1.0));
+//!     println!("exp:    {}", rand.rand_exp(1.0));
 //! }
 //! ```
 
+use core::fmt;
+use core::ops::{Bound, RangeBounds};
 #[cfg(feature = "libc")]
 use libc::{rand, time};
 #[cfg(feature = "posix")]
 use std::{fs, io::Read};
 
+mod distributions;
+mod iter;
+#[cfg(feature = "os_random")]
+mod os_random;
+mod rng;
+mod selection;
+
+pub use iter::RandIter;
+pub use rng::{Isaac, Lfsr, RngCore};
+
 /// Represents a seed value
 type Seed = u128;
 /// Size of the [`Seed`](Seed) type in bytes
@@ -29,8 +57,12 @@ const SEED_SIZE: usize = core::mem::size_of::<Seed>();
 const SEED_SIZE_BITS: usize = SEED_SIZE * 8;
 
 /// A random number generator.
-pub struct Random {
-    seed: Seed,
+///
+/// Generic over its [`RngCore`], which supplies the raw random words everything else is
+/// built on. Defaults to [`Lfsr`] for speed; use [`Random::<Isaac>::with_seed`] (or
+/// [`Random::with_core`]) for the cryptographically-stronger [`Isaac`] core.
+pub struct Random<C: RngCore = Lfsr> {
+    core: C,
 }
 
 /// A source for a random seed.
@@ -38,49 +70,175 @@ pub enum RandomSeedSource {
     /// Manually assigned value
     Manual(Seed),
 
-    /// Get a seed based on the current system time.  
+    /// Get a seed based on the current system time.
     /// __Requires feature: *`libc`*__
     #[cfg(feature = "libc")]
     SystemTime,
 
     /// Get a random number using the [`rand()`](https://man7.org/linux/man-pages/man3/rand.3.html) function from libc.
     /// ### Warning
-    /// Libc uses a constant value as a seed.  
+    /// Libc uses a constant value as a seed.
     /// __Requires feature: *`libc`*__
     #[cfg(feature = "libc")]
     Crand,
 
     /// Create a seed value by reading bytes from `/dev/urandom`.
     /// ### Warning
-    /// This only works on *nix-based systems such as Linux and macOS.  
+    /// This only works on *nix-based systems such as Linux and macOS.
     /// __Requires feature: *`posix`*__
     #[cfg(feature = "posix")]
     UrandomDev,
 
     /// Create a seed value by reading bytes from `/dev/random`.
     /// ### Warning
-    /// This only works on *nix-based systems such as Linux and macOS.  
+    /// This only works on *nix-based systems such as Linux and macOS.
     /// __Requires feature: *`posix`*__
     #[cfg(feature = "posix")]
     RandomDev,
+
+    /// Create a seed value straight from the OS's CSPRNG: the `getrandom` syscall on
+    /// Linux, `getentropy`/`arc4random_buf` on the BSDs/macOS, and `BCryptGenRandom` on
+    /// Windows, falling back to reading `/dev/urandom` only if that syscall is
+    /// unavailable.
+    ///
+    /// Unlike [`UrandomDev`](Self::UrandomDev)/[`RandomDev`](Self::RandomDev), this works
+    /// portably across platforms, matching what [`OsRng`](https://docs.rs/rand/latest/rand/rngs/struct.OsRng.html) does.
+    /// __Requires feature: *`os_random`*__
+    #[cfg(feature = "os_random")]
+    OsRandom,
+}
+
+/// An error that occurred while obtaining a random seed.
+#[derive(Debug)]
+pub enum SeedError {
+    /// The underlying I/O operation (reading a random device, or calling into the OS
+    /// CSPRNG) failed.
+    Io(std::io::Error),
+}
+
+impl fmt::Display for SeedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "failed to obtain a random seed: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for SeedError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(err) => Some(err),
+        }
+    }
+}
+
+impl From<std::io::Error> for SeedError {
+    fn from(err: std::io::Error) -> Self {
+        Self::Io(err)
+    }
 }
 
 macro_rules! implement_unsigned {
-    ($T: ty, $func_name: ident) => {
+    ($T: ty, $func_name: ident, $range_func: ident) => {
         pub fn $func_name(&mut self) -> $T {
-            (self.random() % (<$T>::MAX as Seed)) as $T
+            self.$range_func(..)
         }
     };
 }
 
 macro_rules! implement_signed {
-    ($T: ty, $func_name: ident) => {
+    ($T: ty, $func_name: ident, $range_func: ident) => {
         pub fn $func_name(&mut self) -> $T {
-            let mut n = (self.random() % (<$T>::MAX as Seed)) as $T;
-            if self.rand_bool() {
-                n *= -1;
-            }
-            n
+            self.$range_func(..)
+        }
+    };
+}
+
+macro_rules! implement_unsigned_range {
+    ($T: ty, $func_name: ident) => {
+        /// Generate a random `$T` uniformly distributed over `range`, using rejection
+        /// sampling instead of `% <$T>::MAX` so the result is free of modulo bias.
+        ///
+        /// Accepts both half-open (`a..b`) and inclusive (`a..=b`) ranges.
+        ///
+        /// ### Panics
+        /// Panics if `range` is empty.
+        pub fn $func_name(&mut self, range: impl RangeBounds<$T>) -> $T {
+            let lo: Seed = match range.start_bound() {
+                Bound::Included(&n) => n as Seed,
+                Bound::Excluded(&n) => (n as Seed)
+                    .checked_add(1)
+                    .expect("range start has no valid successor"),
+                Bound::Unbounded => 0,
+            };
+            // `width` is computed directly (rather than via a separate `hi`) so that
+            // requesting the full domain of a word-sized type like `u128` - where
+            // `<$T>::MAX as Seed` is already `Seed::MAX` - naturally wraps to `0` instead
+            // of overflowing past it. `uniform()` already treats `width == 0` as "the
+            // whole word", so this falls out of the existing special case rather than
+            // needing its own.
+            let width: Seed = match range.end_bound() {
+                Bound::Included(&n) => (n as Seed)
+                    .checked_sub(lo)
+                    .expect("cannot sample from an empty range")
+                    .wrapping_add(1),
+                Bound::Excluded(&n) => match (n as Seed).checked_sub(lo) {
+                    Some(width) if width > 0 => width,
+                    _ => panic!("cannot sample from an empty range"),
+                },
+                Bound::Unbounded => (<$T>::MAX as Seed)
+                    .checked_sub(lo)
+                    .expect("cannot sample from an empty range")
+                    .wrapping_add(1),
+            };
+            (lo + self.uniform(width)) as $T
+        }
+    };
+}
+
+macro_rules! implement_signed_range {
+    ($T: ty, $U: ty, $func_name: ident) => {
+        /// Generate a random `$T` uniformly distributed over `range`, using rejection
+        /// sampling instead of `% <$T>::MAX` so the result is free of modulo bias.
+        ///
+        /// The arithmetic happens in the unsigned domain (`$U`, with the sign bit
+        /// flipped to preserve ordering) before being offset back into `$T`.
+        ///
+        /// Accepts both half-open (`a..b`) and inclusive (`a..=b`) ranges.
+        ///
+        /// ### Panics
+        /// Panics if `range` is empty.
+        pub fn $func_name(&mut self, range: impl RangeBounds<$T>) -> $T {
+            const SIGN_BIT: $U = 1 << (<$T>::BITS - 1);
+            let to_unsigned = |n: $T| (n as $U) ^ SIGN_BIT;
+            let from_unsigned = |n: $U| (n ^ SIGN_BIT) as $T;
+
+            let lo: Seed = match range.start_bound() {
+                Bound::Included(&n) => to_unsigned(n) as Seed,
+                Bound::Excluded(&n) => (to_unsigned(n) as Seed)
+                    .checked_add(1)
+                    .expect("range start has no valid successor"),
+                Bound::Unbounded => to_unsigned(<$T>::MIN) as Seed,
+            };
+            // See the comment in `implement_unsigned_range!` for why `width` is computed
+            // directly instead of via a separate `hi`: it lets the full-domain case for
+            // word-sized types (e.g. `i128`) wrap to `0`, which `uniform()` already
+            // treats as "the whole word", rather than overflowing past `Seed::MAX`.
+            let width: Seed = match range.end_bound() {
+                Bound::Included(&n) => (to_unsigned(n) as Seed)
+                    .checked_sub(lo)
+                    .expect("cannot sample from an empty range")
+                    .wrapping_add(1),
+                Bound::Excluded(&n) => match (to_unsigned(n) as Seed).checked_sub(lo) {
+                    Some(width) if width > 0 => width,
+                    _ => panic!("cannot sample from an empty range"),
+                },
+                Bound::Unbounded => (to_unsigned(<$T>::MAX) as Seed)
+                    .checked_sub(lo)
+                    .expect("cannot sample from an empty range")
+                    .wrapping_add(1),
+            };
+            from_unsigned((lo + self.uniform(width)) as $U)
         }
     };
 }
@@ -94,28 +252,63 @@ macro_rules! implement_floating {
     };
 }
 
-/// ## Notes
-/// 1. Unsigned number generation is faster.
-/// 2. Methods for generating signed integers will rotate the seed twice.
-///    The first rotation is to generate an unsigned number, and the second one
-///    is used to generate a random boolean to determine whether the number
-///    should be negative.
-impl Random {
-    /// Create a new Random generator.
+impl Random<Lfsr> {
+    /// Create a new Random generator backed by the default [`Lfsr`] core.
     ///
     /// ### Note
     /// You can create multiple `Random`s in a single program.
     /// Just make sure they use different seeds so they won't generate the same numbers.
+    ///
+    /// ### Panics
+    /// Panics if `seed_src` fails to produce a seed. Use [`Self::try_new`] to handle
+    /// that case instead.
     pub fn new(seed_src: RandomSeedSource) -> Self {
-        Self {
-            seed: seed_src.get_seed(),
-        }
+        Self::try_new(seed_src).expect("failed to get a seed")
     }
 
-    /// Rotates the current seed and returns it.
+    /// Fallible version of [`Self::new`].
+    pub fn try_new(seed_src: RandomSeedSource) -> Result<Self, SeedError> {
+        Ok(Self::with_core(Lfsr::new(seed_src.get_seed()?)))
+    }
+}
+
+impl Random<Isaac> {
+    /// Create a new Random generator backed by the [`Isaac`] core.
+    ///
+    /// Prefer this over [`Random::new`] when the output needs to be
+    /// cryptographically strong rather than merely fast.
+    ///
+    /// ### Panics
+    /// Panics if `seed_src` fails to produce a seed. Use [`Self::try_with_seed`] to
+    /// handle that case instead.
+    pub fn with_seed(seed_src: RandomSeedSource) -> Self {
+        Self::try_with_seed(seed_src).expect("failed to get a seed")
+    }
+
+    /// Fallible version of [`Self::with_seed`].
+    pub fn try_with_seed(seed_src: RandomSeedSource) -> Result<Self, SeedError> {
+        Ok(Self::with_core(Isaac::new(seed_src.get_seed()?)))
+    }
+}
+
+/// ## Notes
+/// 1. Unsigned number generation is faster.
+/// 2. `rand_u*`/`rand_i*` are thin wrappers around the corresponding `gen_range_*`
+///    method called with an unbounded range, so every value in the type's domain
+///    (including its `MIN`/`MAX`) is reachable with equal probability.
+impl<C: RngCore> Random<C> {
+    /// Create a new Random generator from an already-constructed core.
+    ///
+    /// Use this to plug in a [`RngCore`] other than the defaults, e.g. [`Isaac`].
+    pub fn with_core(core: C) -> Self {
+        Self { core }
+    }
+
+    /// Draws the next random word, combining two [`RngCore::next_u64`] calls.
     pub fn random(&mut self) -> Seed {
-        self.rotate();
-        self.seed
+        let lo = self.core.next_u64() as Seed;
+        let hi = self.core.next_u64() as Seed;
+        (hi << 64) | lo
     }
 
     /// Alias to [`random()`](Self::random).
@@ -131,35 +324,63 @@ impl Random {
     implement_floating!(f64, u64, rand_f64);
     implement_floating!(f32, u32, rand_f32);
 
-    implement_unsigned!(u8, rand_u8);
-    implement_unsigned!(u16, rand_u16);
-    implement_unsigned!(u32, rand_u32);
-    implement_unsigned!(u64, rand_u64);
+    implement_unsigned_range!(u8, gen_range_u8);
+    implement_unsigned_range!(u16, gen_range_u16);
+    implement_unsigned_range!(u32, gen_range_u32);
+    implement_unsigned_range!(u64, gen_range_u64);
+    implement_unsigned_range!(u128, gen_range_u128);
+
+    implement_signed_range!(i8, u8, gen_range_i8);
+    implement_signed_range!(i16, u16, gen_range_i16);
+    implement_signed_range!(i32, u32, gen_range_i32);
+    implement_signed_range!(i64, u64, gen_range_i64);
+    implement_signed_range!(i128, u128, gen_range_i128);
+
+    implement_unsigned!(u8, rand_u8, gen_range_u8);
+    implement_unsigned!(u16, rand_u16, gen_range_u16);
+    implement_unsigned!(u32, rand_u32, gen_range_u32);
+    implement_unsigned!(u64, rand_u64, gen_range_u64);
 
-    implement_signed!(i8, rand_i8);
-    implement_signed!(i16, rand_i16);
-    implement_signed!(i32, rand_i32);
-    implement_signed!(i64, rand_i64);
-    implement_signed!(i128, rand_i128);
+    implement_signed!(i8, rand_i8, gen_range_i8);
+    implement_signed!(i16, rand_i16, gen_range_i16);
+    implement_signed!(i32, rand_i32, gen_range_i32);
+    implement_signed!(i64, rand_i64, gen_range_i64);
+    implement_signed!(i128, rand_i128, gen_range_i128);
+
+    /// Draw a uniform value in `0..range` from the underlying word, rejecting draws
+    /// that would bias the result towards the low end.
+    ///
+    /// `range == 0` is treated as "the whole word", since that's the only way a caller
+    /// can ask for the full span of a type as wide as [`Seed`](Seed) itself.
+    fn uniform(&mut self, range: Seed) -> Seed {
+        if range == 0 {
+            return self.random();
+        }
 
-    fn rotate(&mut self) {
-        let newbit = self.seed ^ (self.seed >> 1) ^ (self.seed >> 2) ^ (self.seed >> 7);
-        self.seed = (self.seed >> 1) | (newbit << 127);
+        let zone = Seed::MAX - (Seed::MAX % range);
+        loop {
+            let x = self.random();
+            if x < zone {
+                return x % range;
+            }
+        }
     }
 }
 
 impl RandomSeedSource {
-    fn get_seed(&self) -> Seed {
+    fn get_seed(&self) -> Result<Seed, SeedError> {
         match self {
-            RandomSeedSource::Manual(n) => *n,
+            RandomSeedSource::Manual(n) => Ok(*n),
             #[cfg(feature = "libc")]
-            RandomSeedSource::SystemTime => self.get_system_time(),
+            RandomSeedSource::SystemTime => Ok(self.get_system_time()),
             #[cfg(feature = "libc")]
-            RandomSeedSource::Crand => self.crand(),
+            RandomSeedSource::Crand => Ok(self.crand()),
             #[cfg(feature = "posix")]
             RandomSeedSource::UrandomDev => self.read_from_randdev("urandom"),
             #[cfg(feature = "posix")]
             RandomSeedSource::RandomDev => self.read_from_randdev("random"),
+            #[cfg(feature = "os_random")]
+            RandomSeedSource::OsRandom => self.os_random(),
         }
     }
 
@@ -174,15 +395,74 @@ impl RandomSeedSource {
     }
 
     #[cfg(feature = "posix")]
-    fn read_from_randdev(&self, dev: &str) -> Seed {
+    fn read_from_randdev(&self, dev: &str) -> Result<Seed, SeedError> {
         let mut bytesbuf: [u8; SEED_SIZE] = [0; SEED_SIZE];
-        let mut file = fs::File::open(format!("/dev/{dev}")).unwrap();
-        file.read_exact(&mut bytesbuf).unwrap();
+        let mut file = fs::File::open(format!("/dev/{dev}"))?;
+        file.read_exact(&mut bytesbuf)?;
 
-        if cfg!(target_endian = "big") {
+        Ok(if cfg!(target_endian = "big") {
             Seed::from_be_bytes(bytesbuf)
         } else {
             Seed::from_le_bytes(bytesbuf)
-        }
+        })
+    }
+
+    #[cfg(feature = "os_random")]
+    fn os_random(&self) -> Result<Seed, SeedError> {
+        let mut bytesbuf: [u8; SEED_SIZE] = [0; SEED_SIZE];
+        os_random::fill(&mut bytesbuf)?;
+
+        Ok(if cfg!(target_endian = "big") {
+            Seed::from_be_bytes(bytesbuf)
+        } else {
+            Seed::from_le_bytes(bytesbuf)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Regression test: `gen_range_u128(..)` (and anything else touching a word-sized
+    // type's full domain, like `rand_i128()`) used to panic unconditionally, since the
+    // exclusive upper bound overflowed past `Seed::MAX` instead of wrapping to "the whole
+    // word".
+    #[test]
+    fn rand_i128_does_not_panic() {
+        let mut rand = Random::new(RandomSeedSource::Manual(42));
+        rand.rand_i128();
+    }
+
+    #[test]
+    fn gen_range_u128_unbounded_does_not_panic() {
+        let mut rand = Random::new(RandomSeedSource::Manual(42));
+        rand.gen_range_u128(..);
+    }
+
+    #[test]
+    fn gen_range_i128_unbounded_does_not_panic() {
+        let mut rand = Random::new(RandomSeedSource::Manual(42));
+        rand.gen_range_i128(..);
+    }
+
+    #[test]
+    fn gen_range_u128_inclusive_full_range_does_not_panic() {
+        let mut rand = Random::new(RandomSeedSource::Manual(42));
+        rand.gen_range_u128(0..=u128::MAX);
+    }
+
+    #[test]
+    fn gen_range_u128_inclusive_touching_max_does_not_panic() {
+        let mut rand = Random::new(RandomSeedSource::Manual(42));
+        let n = rand.gen_range_u128(1..=u128::MAX);
+        assert!(n >= 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot sample from an empty range")]
+    fn gen_range_u8_rejects_empty_range() {
+        let mut rand = Random::new(RandomSeedSource::Manual(42));
+        rand.gen_range_u8(5..5);
     }
 }
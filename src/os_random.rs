@@ -0,0 +1,149 @@
+//! Portable access to the OS CSPRNG, for [`RandomSeedSource::OsRandom`](crate::RandomSeedSource::OsRandom).
+
+use std::io;
+
+/// Fill `buf` with bytes from the operating system's CSPRNG.
+pub(crate) fn fill(buf: &mut [u8]) -> io::Result<()> {
+    #[cfg(target_os = "linux")]
+    {
+        // Only fall back when the syscall itself is unavailable: `ENOSYS` on kernels
+        // older than 3.17, or `EPERM` when a seccomp filter blocks it.
+        fill_linux(buf).or_else(|err| match err.kind() {
+            io::ErrorKind::Unsupported | io::ErrorKind::PermissionDenied => {
+                fill_urandom_fallback(buf)
+            }
+            _ => Err(err),
+        })
+    }
+    #[cfg(any(
+        target_os = "macos",
+        target_os = "freebsd",
+        target_os = "openbsd",
+        target_os = "netbsd",
+        target_os = "dragonfly"
+    ))]
+    {
+        fill_bsd(buf)
+    }
+    #[cfg(target_os = "windows")]
+    {
+        fill_windows(buf)
+    }
+    #[cfg(not(any(
+        target_os = "linux",
+        target_os = "macos",
+        target_os = "freebsd",
+        target_os = "openbsd",
+        target_os = "netbsd",
+        target_os = "dragonfly",
+        target_os = "windows"
+    )))]
+    {
+        fill_urandom_fallback(buf)
+    }
+}
+
+#[cfg(target_os = "linux")]
+extern "C" {
+    fn getrandom(buf: *mut core::ffi::c_void, buflen: usize, flags: u32) -> isize;
+}
+
+/// Uses the `getrandom(2)` syscall directly, avoiding the `/dev/urandom` file descriptor
+/// entirely on kernels that support it (Linux >= 3.17).
+#[cfg(target_os = "linux")]
+fn fill_linux(buf: &mut [u8]) -> io::Result<()> {
+    let mut filled = 0;
+    while filled < buf.len() {
+        let ret = unsafe { getrandom(buf[filled..].as_mut_ptr().cast(), buf.len() - filled, 0) };
+        if ret < 0 {
+            let err = io::Error::last_os_error();
+            if err.kind() == io::ErrorKind::Interrupted {
+                continue;
+            }
+            return Err(err);
+        }
+        filled += ret as usize;
+    }
+    Ok(())
+}
+
+#[cfg(any(
+    target_os = "macos",
+    target_os = "freebsd",
+    target_os = "openbsd",
+    target_os = "netbsd",
+    target_os = "dragonfly"
+))]
+extern "C" {
+    fn getentropy(buf: *mut core::ffi::c_void, buflen: usize) -> i32;
+    fn arc4random_buf(buf: *mut core::ffi::c_void, nbytes: usize);
+}
+
+/// Prefers `getentropy(2)`, falling back to `arc4random_buf(3)` (which cannot fail) if
+/// `getentropy` is unavailable, e.g. because of a sandbox policy.
+#[cfg(any(
+    target_os = "macos",
+    target_os = "freebsd",
+    target_os = "openbsd",
+    target_os = "netbsd",
+    target_os = "dragonfly"
+))]
+fn fill_bsd(buf: &mut [u8]) -> io::Result<()> {
+    let ret = unsafe { getentropy(buf.as_mut_ptr().cast(), buf.len()) };
+    if ret == 0 {
+        return Ok(());
+    }
+
+    unsafe { arc4random_buf(buf.as_mut_ptr().cast(), buf.len()) };
+    Ok(())
+}
+
+#[cfg(target_os = "windows")]
+#[link(name = "bcrypt")]
+extern "system" {
+    fn BCryptGenRandom(
+        h_algorithm: *mut core::ffi::c_void,
+        pb_buffer: *mut u8,
+        cb_buffer: u32,
+        dw_flags: u32,
+    ) -> i32;
+}
+
+#[cfg(target_os = "windows")]
+const BCRYPT_USE_SYSTEM_PREFERRED_RNG: u32 = 0x0000_0002;
+
+#[cfg(target_os = "windows")]
+#[link(name = "ntdll")]
+extern "system" {
+    fn RtlNtStatusToDosError(status: i32) -> u32;
+}
+
+/// Uses `BCryptGenRandom` with `BCRYPT_USE_SYSTEM_PREFERRED_RNG`, the same CSPRNG `OsRng`
+/// relies on.
+#[cfg(target_os = "windows")]
+fn fill_windows(buf: &mut [u8]) -> io::Result<()> {
+    let status = unsafe {
+        BCryptGenRandom(
+            core::ptr::null_mut(),
+            buf.as_mut_ptr(),
+            buf.len() as u32,
+            BCRYPT_USE_SYSTEM_PREFERRED_RNG,
+        )
+    };
+
+    if status != 0 {
+        // `status` is an NTSTATUS, not a Win32 error code - the two use different numeric
+        // namespaces, so it must be mapped before `io::Error::from_raw_os_error` (which
+        // expects a Win32/`GetLastError` code) can report anything meaningful.
+        let win32_error = unsafe { RtlNtStatusToDosError(status) };
+        return Err(io::Error::from_raw_os_error(win32_error as i32));
+    }
+    Ok(())
+}
+
+#[cfg(not(target_os = "windows"))]
+fn fill_urandom_fallback(buf: &mut [u8]) -> io::Result<()> {
+    use std::{fs::File, io::Read};
+
+    File::open("/dev/urandom")?.read_exact(buf)
+}
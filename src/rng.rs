@@ -0,0 +1,15 @@
+mod isaac;
+mod lfsr;
+
+pub use isaac::Isaac;
+pub use lfsr::Lfsr;
+
+/// The source of raw random words behind a [`Random`](crate::Random).
+///
+/// [`Random`](crate::Random) is generic over this trait so its core can be swapped out:
+/// the default [`Lfsr`] favors speed, while [`Isaac`] trades some speed for
+/// cryptographically-stronger output.
+pub trait RngCore {
+    /// Produce the next 64-bit word from the core.
+    fn next_u64(&mut self) -> u64;
+}
@@ -0,0 +1,217 @@
+use super::RngCore;
+
+const WORDS: usize = 256;
+
+/// [ISAAC](https://www.burtleburtle.net/bob/rand/isaacafa.html), a cryptographically-strong
+/// alternative core to [`Lfsr`](super::Lfsr).
+///
+/// ISAAC keeps a 256-word state array (`mem`), an output array (`rsl`), and three
+/// accumulators (`aa`, `bb`, `cc`). Every 256 words drawn it refills `rsl` by mixing `mem`
+/// through a barrel-shifted pass over `aa`/`bb`/`cc`.
+pub struct Isaac {
+    mem: [u32; WORDS],
+    rsl: [u32; WORDS],
+    aa: u32,
+    bb: u32,
+    cc: u32,
+    /// Number of words already handed out from the current `rsl` fill.
+    used: usize,
+}
+
+impl Isaac {
+    /// Create an ISAAC core seeded with the given 128-bit state.
+    pub fn new(seed: u128) -> Self {
+        let mut rsl = [0u32; WORDS];
+        for (word, chunk) in rsl.iter_mut().zip(seed.to_le_bytes().chunks(4)) {
+            let mut buf = [0u8; 4];
+            buf[..chunk.len()].copy_from_slice(chunk);
+            *word = u32::from_le_bytes(buf);
+        }
+
+        let mut isaac = Self {
+            mem: [0; WORDS],
+            rsl,
+            aa: 0,
+            bb: 0,
+            cc: 0,
+            used: WORDS,
+        };
+        isaac.init();
+        isaac
+    }
+
+    /// Jenkins' golden-ratio scramble, used by [`Self::init`] to spread the seed across
+    /// `mem`.
+    fn mix(state: &mut [u32; 8]) {
+        let [a, b, c, d, e, f, g, h] = state;
+        *a ^= *b << 11;
+        *d = d.wrapping_add(*a);
+        *b = b.wrapping_add(*c);
+        *b ^= *c >> 2;
+        *e = e.wrapping_add(*b);
+        *c = c.wrapping_add(*d);
+        *c ^= *d << 8;
+        *f = f.wrapping_add(*c);
+        *d = d.wrapping_add(*e);
+        *d ^= *e >> 16;
+        *g = g.wrapping_add(*d);
+        *e = e.wrapping_add(*f);
+        *e ^= *f << 10;
+        *h = h.wrapping_add(*e);
+        *f = f.wrapping_add(*g);
+        *f ^= *g >> 4;
+        *a = a.wrapping_add(*f);
+        *g = g.wrapping_add(*h);
+        *g ^= *h << 8;
+        *b = b.wrapping_add(*g);
+        *h = h.wrapping_add(*a);
+        *h ^= *a >> 9;
+        *c = c.wrapping_add(*h);
+        *a = a.wrapping_add(*b);
+    }
+
+    fn init(&mut self) {
+        let mut state = [0x9e3779b9u32; 8];
+        for _ in 0..4 {
+            Self::mix(&mut state);
+        }
+
+        for i in (0..WORDS).step_by(8) {
+            for (word, seed_word) in state.iter_mut().zip(&self.rsl[i..i + 8]) {
+                *word = word.wrapping_add(*seed_word);
+            }
+            Self::mix(&mut state);
+            self.mem[i..i + 8].copy_from_slice(&state);
+        }
+
+        for i in (0..WORDS).step_by(8) {
+            for (word, mem_word) in state.iter_mut().zip(&self.mem[i..i + 8]) {
+                *word = word.wrapping_add(*mem_word);
+            }
+            Self::mix(&mut state);
+            self.mem[i..i + 8].copy_from_slice(&state);
+        }
+
+        self.refill();
+    }
+
+    /// Regenerate all 256 words of `rsl` from `mem`, advancing `aa`/`bb`/`cc`.
+    fn refill(&mut self) {
+        self.cc = self.cc.wrapping_add(1);
+        self.bb = self.bb.wrapping_add(self.cc);
+
+        for i in 0..WORDS {
+            let x = self.mem[i];
+            self.aa = match i % 4 {
+                0 => self.aa ^ (self.aa << 13),
+                1 => self.aa ^ (self.aa >> 6),
+                2 => self.aa ^ (self.aa << 2),
+                _ => self.aa ^ (self.aa >> 16),
+            };
+            self.aa = self.aa.wrapping_add(self.mem[(i + 128) % WORDS]);
+
+            let y = self.mem[((x >> 2) as usize) % WORDS]
+                .wrapping_add(self.aa)
+                .wrapping_add(self.bb);
+            self.mem[i] = y;
+
+            self.bb = self.mem[((y >> 10) as usize) % WORDS].wrapping_add(x);
+            self.rsl[i] = self.bb;
+        }
+
+        self.used = 0;
+    }
+
+    fn next_u32(&mut self) -> u32 {
+        if self.used >= WORDS {
+            self.refill();
+        }
+
+        // Drain in reverse, as the reference implementation does.
+        let value = self.rsl[WORDS - 1 - self.used];
+        self.used += 1;
+        value
+    }
+}
+
+impl RngCore for Isaac {
+    fn next_u64(&mut self) -> u64 {
+        let lo = self.next_u32() as u64;
+        let hi = self.next_u32() as u64;
+        (hi << 32) | lo
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Pins the exact output sequence for two fixed seeds, so a subtly wrong shift amount
+    // or index derivation in `mix`/`refill` shows up as a test failure instead of just
+    // silently shipping a weaker "cryptographically stronger" core. These values were
+    // captured from this implementation directly (no independent reference `isaac.c` run
+    // was available in this environment) - the real value of this test is as a tripwire
+    // against future regressions, not as external validation of correctness.
+    #[test]
+    fn next_u64_matches_known_sequence_for_seed_42() {
+        let mut isaac = Isaac::new(42);
+        let sequence: Vec<u64> = (0..4).map(|_| isaac.next_u64()).collect();
+        assert_eq!(
+            sequence,
+            vec![
+                1093928455445628269,
+                5260798848302465882,
+                16050316995557932011,
+                8294639974497229956,
+            ]
+        );
+    }
+
+    #[test]
+    fn next_u64_matches_known_sequence_for_seed_0() {
+        let mut isaac = Isaac::new(0);
+        let sequence: Vec<u64> = (0..4).map(|_| isaac.next_u64()).collect();
+        assert_eq!(
+            sequence,
+            vec![
+                3461942708420346099,
+                12721205639886104098,
+                11047900434385589881,
+                1137612062340393141,
+            ]
+        );
+    }
+
+    /// A correctly-mixed core should set roughly half its output bits over many draws.
+    /// This won't catch every possible mixing bug, but a badly broken shift/XOR (e.g. one
+    /// that always clears or always sets a given bit) will blow well past the tolerance.
+    #[test]
+    fn output_bits_are_roughly_balanced() {
+        let mut isaac = Isaac::new(1234);
+        let draws = 20_000;
+        let mut ones = 0u64;
+
+        for _ in 0..draws {
+            ones += isaac.next_u64().count_ones() as u64;
+        }
+
+        let total_bits = draws * 64;
+        let fraction_ones = ones as f64 / total_bits as f64;
+        assert!(
+            (0.49..=0.51).contains(&fraction_ones),
+            "expected roughly 50% of output bits set, got {:.4}",
+            fraction_ones
+        );
+    }
+
+    /// Re-seeding resets the stream, so the same seed must reproduce the same output.
+    #[test]
+    fn same_seed_reproduces_same_output() {
+        let mut a = Isaac::new(7);
+        let mut b = Isaac::new(7);
+
+        for _ in 0..(WORDS * 2) {
+            assert_eq!(a.next_u64(), b.next_u64());
+        }
+    }
+}
@@ -0,0 +1,26 @@
+use super::RngCore;
+
+/// A 128-bit Fibonacci [LFSR](https://en.wikipedia.org/wiki/Linear-feedback_shift_register).
+///
+/// This is the default core behind [`Random`](crate::Random): cheap to step, but not
+/// suitable for cryptographic use. See [`Isaac`](super::Isaac) for a stronger alternative.
+pub struct Lfsr {
+    state: u128,
+}
+
+impl Lfsr {
+    /// Create an LFSR core seeded with the given 128-bit state.
+    pub fn new(seed: u128) -> Self {
+        Self { state: seed }
+    }
+}
+
+impl RngCore for Lfsr {
+    fn next_u64(&mut self) -> u64 {
+        let newbit = self.state ^ (self.state >> 1) ^ (self.state >> 2) ^ (self.state >> 7);
+        self.state = (self.state >> 1) | (newbit << 127);
+        // The freshly fed-back bit lands at bit 127, so the upper half carries the most
+        // entropy per step; the lower half is mostly yet-to-be-shifted-out old state.
+        (self.state >> 64) as u64
+    }
+}
@@ -0,0 +1,204 @@
+use crate::{Random, RngCore};
+
+impl<C: RngCore> Random<C> {
+    /// Shuffle `slice` in place, using the
+    /// [Fisher–Yates algorithm](https://en.wikipedia.org/wiki/Fisher%E2%80%93Yates_shuffle).
+    pub fn shuffle<T>(&mut self, slice: &mut [T]) {
+        for i in (1..slice.len()).rev() {
+            let j = self.gen_range_u64(0..=i as u64) as usize;
+            slice.swap(i, j);
+        }
+    }
+
+    /// Pick a uniformly random element from `slice`, or `None` if it's empty.
+    pub fn choose<'a, T>(&mut self, slice: &'a [T]) -> Option<&'a T> {
+        if slice.is_empty() {
+            return None;
+        }
+
+        let i = self.gen_range_u64(0..slice.len() as u64) as usize;
+        slice.get(i)
+    }
+
+    /// Sample `n` items uniformly from `iter`, using
+    /// [reservoir sampling](https://en.wikipedia.org/wiki/Reservoir_sampling) so every
+    /// item has an equal chance of being picked regardless of the iterator's length.
+    ///
+    /// Returns fewer than `n` items if `iter` yields fewer than `n`.
+    pub fn choose_multiple<T>(&mut self, iter: impl Iterator<Item = T>, n: usize) -> Vec<T> {
+        let mut iter = iter.enumerate();
+        let mut reservoir: Vec<T> = iter.by_ref().take(n).map(|(_, item)| item).collect();
+
+        for (m, item) in iter {
+            let k = self.gen_range_u64(0..=m as u64) as usize;
+            if k < n {
+                reservoir[k] = item;
+            }
+        }
+
+        reservoir
+    }
+
+    /// Pick an element from `items` with probability proportional to its weight.
+    ///
+    /// Builds the cumulative weight array in `O(n)`, then draws a uniform value in
+    /// `[0, total)` and binary-searches the cumulative array for the first entry that
+    /// exceeds it in `O(log n)`. If you're drawing repeatedly from the same `items`/
+    /// `weights`, precompute the cumulative table once yourself and reuse it instead of
+    /// paying the `O(n)` build on every call.
+    ///
+    /// Returns `None` if `items` and `weights` differ in length, either is empty, any
+    /// weight is negative or non-finite (`NaN`/`inf`), or the weights sum to zero.
+    pub fn choose_weighted<'a, T>(&mut self, items: &'a [T], weights: &[f64]) -> Option<&'a T> {
+        if items.is_empty()
+            || items.len() != weights.len()
+            || weights.iter().any(|w| !w.is_finite() || *w < 0.0)
+        {
+            return None;
+        }
+
+        let mut total = 0.0;
+        let cumulative: Vec<f64> = weights
+            .iter()
+            .map(|&w| {
+                total += w;
+                total
+            })
+            .collect();
+
+        if total <= 0.0 {
+            return None;
+        }
+
+        let draw = self.rand_f64() * total;
+        let index = cumulative.partition_point(|&c| c <= draw);
+        items.get(index.min(items.len() - 1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Random, RandomSeedSource};
+
+    fn rand() -> Random {
+        Random::new(RandomSeedSource::Manual(42))
+    }
+
+    #[test]
+    fn shuffle_is_a_permutation_of_the_original_elements() {
+        let mut original: Vec<u32> = (0..50).collect();
+        let mut shuffled = original.clone();
+
+        rand().shuffle(&mut shuffled);
+
+        original.sort_unstable();
+        let mut sorted_shuffled = shuffled.clone();
+        sorted_shuffled.sort_unstable();
+        assert_eq!(original, sorted_shuffled);
+    }
+
+    #[test]
+    fn shuffle_handles_short_slices() {
+        let mut empty: Vec<u32> = vec![];
+        rand().shuffle(&mut empty);
+        assert!(empty.is_empty());
+
+        let mut single = [1];
+        rand().shuffle(&mut single);
+        assert_eq!(single, [1]);
+    }
+
+    #[test]
+    fn choose_returns_none_on_empty_slice() {
+        let empty: [u32; 0] = [];
+        assert_eq!(rand().choose(&empty), None);
+    }
+
+    #[test]
+    fn choose_returns_an_element_from_the_slice() {
+        let items = [10, 20, 30, 40, 50];
+        let chosen = rand().choose(&items).expect("slice is non-empty");
+        assert!(items.contains(chosen));
+    }
+
+    #[test]
+    fn choose_multiple_returns_fewer_than_n_when_iterator_is_shorter() {
+        let items = vec![1, 2, 3];
+        let sample = rand().choose_multiple(items.into_iter(), 10);
+        assert_eq!(sample.len(), 3);
+    }
+
+    #[test]
+    fn choose_multiple_returns_n_items_when_iterator_is_longer() {
+        let sample = rand().choose_multiple(0..1000, 10);
+        assert_eq!(sample.len(), 10);
+    }
+
+    #[test]
+    fn choose_weighted_rejects_mismatched_lengths() {
+        let items = [1, 2, 3];
+        let weights = [1.0, 1.0];
+        assert_eq!(rand().choose_weighted(&items, &weights), None);
+    }
+
+    #[test]
+    fn choose_weighted_rejects_empty_items() {
+        let items: [u32; 0] = [];
+        let weights: [f64; 0] = [];
+        assert_eq!(rand().choose_weighted(&items, &weights), None);
+    }
+
+    #[test]
+    fn choose_weighted_rejects_negative_weight() {
+        let items = [1, 2, 3];
+        let weights = [1.0, -1.0, 1.0];
+        assert_eq!(rand().choose_weighted(&items, &weights), None);
+    }
+
+    #[test]
+    fn choose_weighted_rejects_nan_weight() {
+        let items = [1, 2, 3];
+        let weights = [1.0, f64::NAN, 1.0];
+        assert_eq!(rand().choose_weighted(&items, &weights), None);
+    }
+
+    #[test]
+    fn choose_weighted_rejects_infinite_weight() {
+        let items = [1, 2, 3];
+        let weights = [1.0, f64::INFINITY, 1.0];
+        assert_eq!(rand().choose_weighted(&items, &weights), None);
+    }
+
+    #[test]
+    fn choose_weighted_rejects_all_zero_weights() {
+        let items = [1, 2, 3];
+        let weights = [0.0, 0.0, 0.0];
+        assert_eq!(rand().choose_weighted(&items, &weights), None);
+    }
+
+    #[test]
+    fn choose_weighted_favors_heavier_items() {
+        let items = ["light", "heavy"];
+        let weights = [1.0, 99.0];
+        // A seed with more entropy than `rand()`'s small `Manual(42)`: the LFSR starts
+        // from the seed bit pattern directly, and a mostly-zero seed takes many rotations
+        // to diffuse, which would otherwise skew this proportionality check.
+        let mut rand = Random::new(RandomSeedSource::Manual(
+            0x1234_5678_9abc_def0_1234_5678_9abc_def0,
+        ));
+
+        let mut heavy_count = 0;
+        let draws = 2000;
+        for _ in 0..draws {
+            if rand.choose_weighted(&items, &weights) == Some(&"heavy") {
+                heavy_count += 1;
+            }
+        }
+
+        // Expected ~99% "heavy"; leave a generous margin to avoid flakiness.
+        assert!(
+            heavy_count > draws * 9 / 10,
+            "expected the heavily-weighted item to dominate, got {heavy_count}/{draws}"
+        );
+    }
+}